@@ -0,0 +1,231 @@
+#[cfg(target_family = "windows")]
+pub use win_gateway::*;
+
+#[cfg(target_family = "unix")]
+pub use linux_gateway::*;
+
+#[cfg(target_family = "unix")]
+mod linux_gateway {
+    use crate::{Interface, MacAddr, interfaces};
+    use std::{fs, io, net::Ipv4Addr};
+
+    #[derive(Clone, Debug)]
+    pub struct Gateway {
+        pub ip_addr: Ipv4Addr,
+        pub mac_addr: Option<MacAddr>,
+        pub interface: Interface,
+    }
+
+    /// Resolve the default IPv4 gateway by reading the kernel's routing table.
+    pub fn default_gateway() -> io::Result<Gateway> {
+        let (iface_name, ip_addr) = default_route()?;
+
+        let interface = interfaces()?
+            .into_iter()
+            .find(|iface| iface.name == iface_name)
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "gateway interface not found")
+            })?;
+
+        let mac_addr = arp_lookup(ip_addr);
+
+        Ok(Gateway {
+            ip_addr,
+            mac_addr,
+            interface,
+        })
+    }
+
+    fn default_route() -> io::Result<(String, Ipv4Addr)> {
+        let route_table = fs::read_to_string("/proc/net/route")?;
+        parse_default_route(&route_table)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no default route found"))
+    }
+
+    /// Parse the default (`00000000` destination) route out of the text of
+    /// `/proc/net/route`. Each gateway field is a little-endian hex `u32`, so
+    /// it needs a byte swap before it matches network byte order.
+    fn parse_default_route(route_table: &str) -> Option<(String, Ipv4Addr)> {
+        route_table.lines().skip(1).find_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = fields.next()?;
+            let destination = fields.next()?;
+            let gateway_hex = fields.next()?;
+            if destination != "00000000" {
+                return None;
+            }
+
+            let gateway_bits = u32::from_str_radix(gateway_hex, 16).ok()?;
+            Some((name.to_string(), Ipv4Addr::from_bits(gateway_bits.swap_bytes())))
+        })
+    }
+
+    #[cfg(test)]
+    mod parse_default_route_tests {
+        use super::*;
+
+        const ROUTE_TABLE: &str = "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT\n\
+eth0\t00000000\t0202A8C0\t0003\t0\t0\t0\t00000000\t0\t0\t0\n\
+eth0\t0002A8C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\t0\t0\t0\n";
+
+        #[test]
+        fn finds_the_default_route_and_byte_swaps_the_gateway() {
+            let (name, gateway) = parse_default_route(ROUTE_TABLE).expect("a default route");
+            assert_eq!(name, "eth0");
+            assert_eq!(gateway, Ipv4Addr::new(192, 168, 2, 2));
+        }
+
+        #[test]
+        fn returns_none_without_a_default_destination() {
+            let non_default_only = "Iface\tDestination\tGateway\n\
+eth0\t0002A8C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\t0\t0\t0\n";
+            assert_eq!(parse_default_route(non_default_only), None);
+        }
+    }
+
+    fn arp_lookup(ip_addr: Ipv4Addr) -> Option<MacAddr> {
+        let arp_table = fs::read_to_string("/proc/net/arp").ok()?;
+
+        arp_table.lines().skip(1).find_map(|line| {
+            let mut fields = line.split_whitespace();
+            let ip: Ipv4Addr = fields.next()?.parse().ok()?;
+            if ip != ip_addr {
+                return None;
+            }
+            fields.next()?; // HW type
+            fields.next()?; // Flags
+            let hw_addr = fields.next()?;
+
+            let mut mac = [0u8; 6];
+            for (byte, part) in mac.iter_mut().zip(hw_addr.split(':')) {
+                *byte = u8::from_str_radix(part, 16).ok()?;
+            }
+            Some(MacAddr(mac))
+        })
+    }
+}
+
+#[cfg(target_family = "windows")]
+mod win_gateway {
+    use crate::{Interface, MacAddr, interfaces};
+    use std::io;
+    use std::net::Ipv4Addr;
+
+    use windows::Win32::Foundation::ERROR_BUFFER_OVERFLOW;
+    use windows::Win32::Foundation::ERROR_SUCCESS;
+    use windows::Win32::Foundation::WIN32_ERROR;
+    use windows::Win32::NetworkManagement::IpHelper::GAA_FLAG_INCLUDE_GATEWAYS;
+    use windows::Win32::NetworkManagement::IpHelper::GAA_FLAG_SKIP_ANYCAST;
+    use windows::Win32::NetworkManagement::IpHelper::GAA_FLAG_SKIP_DNS_SERVER;
+    use windows::Win32::NetworkManagement::IpHelper::GetAdaptersAddresses;
+    use windows::Win32::NetworkManagement::IpHelper::IP_ADAPTER_ADDRESSES_LH;
+    use windows::Win32::NetworkManagement::IpHelper::SendARP;
+    use windows::Win32::Networking::WinSock::AF_INET;
+    use windows::Win32::Networking::WinSock::AF_UNSPEC;
+    use windows::Win32::Networking::WinSock::SOCKADDR_IN;
+
+    #[derive(Clone, Debug)]
+    pub struct Gateway {
+        pub ip_addr: Ipv4Addr,
+        pub mac_addr: Option<MacAddr>,
+        pub interface: Interface,
+    }
+
+    /// Resolve the default IPv4 gateway, including its MAC address.
+    pub fn default_gateway() -> io::Result<Gateway> {
+        unsafe {
+            let mut buffer_size = 15000u32;
+            let family = AF_UNSPEC.0 as u32;
+            let mut buffer;
+            let mut adapters;
+
+            loop {
+                buffer = vec![0u8; buffer_size as usize];
+                adapters = buffer.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH;
+
+                let res = GetAdaptersAddresses(
+                    family,
+                    GAA_FLAG_INCLUDE_GATEWAYS | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_DNS_SERVER,
+                    None,
+                    Some(adapters),
+                    &mut buffer_size,
+                );
+
+                match WIN32_ERROR(res) {
+                    ERROR_SUCCESS => break,
+                    ERROR_BUFFER_OVERFLOW => continue,
+                    _ => return Err(io::Error::last_os_error()),
+                }
+            }
+
+            let (name, ip_addr) = find_default_gateway(adapters).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "no default gateway found")
+            })?;
+
+            let interface = interfaces()?
+                .into_iter()
+                .find(|iface| iface.name == name)
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, "gateway interface not found")
+                })?;
+
+            let mac_addr = resolve_mac(ip_addr);
+
+            Ok(Gateway {
+                ip_addr,
+                mac_addr,
+                interface,
+            })
+        }
+    }
+
+    unsafe fn find_default_gateway(
+        mut node: *mut IP_ADAPTER_ADDRESSES_LH,
+    ) -> Option<(String, Ipv4Addr)> {
+        unsafe {
+            while let Some(adapter) = node.as_ref() {
+                let Some(gateway) = adapter.FirstGatewayAddress.as_ref() else {
+                    node = adapter.Next;
+                    continue;
+                };
+
+                let Some(sockaddr) = gateway.Address.lpSockaddr.as_ref() else {
+                    node = adapter.Next;
+                    continue;
+                };
+                if sockaddr.sa_family != AF_INET {
+                    node = adapter.Next;
+                    continue;
+                }
+
+                let (Some(sockaddr_in), Ok(name)) = (
+                    gateway.Address.lpSockaddr.cast::<SOCKADDR_IN>().as_ref(),
+                    adapter.FriendlyName.to_string(),
+                ) else {
+                    node = adapter.Next;
+                    continue;
+                };
+
+                let ip = Ipv4Addr::from_bits(u32::from_be(sockaddr_in.sin_addr.S_un.S_addr));
+                return Some((name, ip));
+            }
+            None
+        }
+    }
+
+    fn resolve_mac(ip_addr: Ipv4Addr) -> Option<MacAddr> {
+        let dest = u32::from_ne_bytes(ip_addr.octets());
+        let mut mac = [0u32; 2];
+        let mut len = 6u32;
+
+        let res = unsafe { SendARP(dest, 0, mac.as_mut_ptr().cast(), &mut len) };
+        if res != 0 || len != 6 {
+            return None;
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(mac.as_ptr().cast::<u8>(), 6) };
+        let mut out = [0u8; 6];
+        out.copy_from_slice(bytes);
+        Some(MacAddr(out))
+    }
+}
@@ -1,23 +1,76 @@
 #[cfg(target_family = "windows")]
 pub use winders::*;
 
-#[cfg(target_family = "unix")]
+#[cfg(all(target_family = "unix", not(target_os = "android")))]
 pub use linux::*;
 
-#[cfg(target_family = "unix")]
+#[cfg(target_os = "android")]
+pub use android::*;
+
+pub mod gateway;
+
+#[cfg(all(target_family = "unix", not(target_os = "android")))]
 pub mod linux {
-    use libc::{AF_INET, IFF_LOOPBACK, IFF_LOWER_UP, getifaddrs, ifaddrs, sockaddr_in};
-    use std::{ffi::CStr, io, net::Ipv4Addr};
+    use libc::{
+        AF_INET, AF_INET6, AF_PACKET, IFF_LOOPBACK, IFF_LOWER_UP, IFF_POINTOPOINT, freeifaddrs,
+        getifaddrs, if_nametoindex, ifaddrs, sockaddr_in, sockaddr_in6, sockaddr_ll,
+    };
+    use std::{
+        ffi::CStr,
+        fmt,
+        io,
+        net::{Ipv4Addr, Ipv6Addr},
+    };
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct MacAddr(pub [u8; 6]);
+
+    impl fmt::Display for MacAddr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let [a, b, c, d, e, f_] = self.0;
+            write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{f_:02x}")
+        }
+    }
 
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Ipv4Interface {
-        pub name: String,
+    pub struct Ifv4Addr {
         pub ip: Ipv4Addr,
+        pub netmask: Ipv4Addr,
+        pub broadcast: Option<Ipv4Addr>,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Ifv6Addr {
+        pub ip: Ipv6Addr,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum IfAddr {
+        V4(Ifv4Addr),
+        V6(Ifv6Addr),
+    }
+
+    impl IfAddr {
+        /// Returns `true` if the address is link-local (169.254.0.0 or fe80::/10)
+        pub fn is_link_local(&self) -> bool {
+            match self {
+                IfAddr::V4(addr) => addr.ip.is_link_local(),
+                IfAddr::V6(addr) => addr.ip.is_unicast_link_local(),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Interface {
+        pub name: String,
+        pub addrs: Vec<IfAddr>,
         pub flags: u32,
+        pub mac_addr: Option<MacAddr>,
+        pub index: u32,
     }
 
-    impl Ipv4Interface {
-        /// Returns `true` if this is a loopback address (127.0.0.0)
+    impl Interface {
+        /// Returns `true` if this is a loopback interface
         pub fn is_loopback(&self) -> bool {
             self.flags & IFF_LOOPBACK as u32 != 0
         }
@@ -26,58 +79,697 @@ pub mod linux {
         pub fn is_lower_up(&self) -> bool {
             self.flags & IFF_LOWER_UP as u32 != 0
         }
+    }
+
+    /// Collect all IPv4 and IPv6 network interfaces. Interfaces are not
+    /// filtered by link state; use `is_lower_up` to check it.
+    pub fn interfaces() -> Result<Vec<Interface>, io::Error> {
+        let mut head = std::ptr::null_mut();
+        let ret = unsafe { getifaddrs(&raw mut head) };
+        if ret == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Extract every field we need while the list is still alive: `ifaddrs`
+        // entries hold pointers into the allocation `freeifaddrs` releases, so
+        // nothing here may outlive the single `freeifaddrs(head)` call below.
+        let mut interfaces: Vec<Interface> = Vec::new();
+        let mut node = head;
+        while let Some(addr) = unsafe { node.as_ref() } {
+            if let Some((name, flags, if_addr)) = to_if_addr(addr) {
+                match interfaces.iter_mut().find(|iface| iface.name == name) {
+                    Some(iface) => iface.addrs.push(if_addr),
+                    None => {
+                        let index = if_nametoindex_for(&name);
+                        interfaces.push(Interface {
+                            name,
+                            flags,
+                            addrs: vec![if_addr],
+                            mac_addr: None,
+                            index,
+                        });
+                    }
+                }
+            } else if let Some((name, mac_addr)) = to_mac_addr(addr) {
+                match interfaces.iter_mut().find(|iface| iface.name == name) {
+                    Some(iface) => iface.mac_addr = Some(mac_addr),
+                    None => {
+                        let index = if_nametoindex_for(&name);
+                        interfaces.push(Interface {
+                            name,
+                            flags: addr.ifa_flags,
+                            addrs: Vec::new(),
+                            mac_addr: Some(mac_addr),
+                            index,
+                        });
+                    }
+                }
+            }
+
+            node = addr.ifa_next;
+        }
+
+        unsafe { freeifaddrs(head) };
+        Ok(interfaces)
+    }
+
+    fn if_nametoindex_for(name: &str) -> u32 {
+        let Ok(c_name) = std::ffi::CString::new(name) else {
+            return 0;
+        };
+        unsafe { if_nametoindex(c_name.as_ptr()) }
+    }
+
+    fn to_mac_addr(addr: &ifaddrs) -> Option<(String, MacAddr)> {
+        let sockaddr = unsafe { addr.ifa_addr.as_ref()? };
+        if i32::from(sockaddr.sa_family) != AF_PACKET {
+            return None;
+        }
+
+        let name = unsafe { CStr::from_ptr(addr.ifa_name) }
+            .to_string_lossy()
+            .to_string();
+        let sockaddr_ll = unsafe { addr.ifa_addr.cast::<sockaddr_ll>().as_ref()? };
+        let mac = [
+            sockaddr_ll.sll_addr[0],
+            sockaddr_ll.sll_addr[1],
+            sockaddr_ll.sll_addr[2],
+            sockaddr_ll.sll_addr[3],
+            sockaddr_ll.sll_addr[4],
+            sockaddr_ll.sll_addr[5],
+        ];
+
+        Some((name, MacAddr(mac)))
+    }
+
+    fn to_if_addr(addr: &ifaddrs) -> Option<(String, u32, IfAddr)> {
+        let sockaddr = unsafe { addr.ifa_addr.as_ref()? };
+        let name = unsafe { CStr::from_ptr(addr.ifa_name) }
+            .to_string_lossy()
+            .to_string();
+        let flags = addr.ifa_flags;
+
+        match i32::from(sockaddr.sa_family) {
+            AF_INET => {
+                let sockaddr_in = unsafe { addr.ifa_addr.cast::<sockaddr_in>().as_ref()? };
+                let ip = Ipv4Addr::from_bits(u32::from_be(sockaddr_in.sin_addr.s_addr));
+
+                let netmask_in = unsafe { addr.ifa_netmask.cast::<sockaddr_in>().as_ref() };
+                let netmask = netmask_in
+                    .map(|n| Ipv4Addr::from_bits(u32::from_be(n.sin_addr.s_addr)))
+                    .unwrap_or(Ipv4Addr::UNSPECIFIED);
+
+                let is_p2p_or_loopback =
+                    flags & (IFF_LOOPBACK | IFF_POINTOPOINT) as u32 != 0;
+                let broadcast = ipv4_broadcast(ip, netmask, is_p2p_or_loopback);
+
+                Some((
+                    name,
+                    flags,
+                    IfAddr::V4(Ifv4Addr {
+                        ip,
+                        netmask,
+                        broadcast,
+                    }),
+                ))
+            }
+            AF_INET6 => {
+                let sockaddr_in6 = unsafe { addr.ifa_addr.cast::<sockaddr_in6>().as_ref()? };
+                let ip = Ipv6Addr::from(sockaddr_in6.sin6_addr.s6_addr);
+                Some((name, flags, IfAddr::V6(Ifv6Addr { ip })))
+            }
+            _ => None,
+        }
+    }
+
+    /// Derive the directed broadcast address for `ip`/`netmask`, or `None` when
+    /// the interface is loopback/point-to-point or the netmask is unknown.
+    fn ipv4_broadcast(ip: Ipv4Addr, netmask: Ipv4Addr, is_p2p_or_loopback: bool) -> Option<Ipv4Addr> {
+        (!is_p2p_or_loopback && netmask != Ipv4Addr::UNSPECIFIED)
+            .then(|| Ipv4Addr::from_bits(ip.to_bits() | !netmask.to_bits()))
+    }
+
+    #[cfg(test)]
+    mod ipv4_broadcast_tests {
+        use super::*;
+
+        #[test]
+        fn computes_directed_broadcast() {
+            let ip = Ipv4Addr::new(192, 168, 1, 42);
+            let netmask = Ipv4Addr::new(255, 255, 255, 0);
+            assert_eq!(
+                ipv4_broadcast(ip, netmask, false),
+                Some(Ipv4Addr::new(192, 168, 1, 255))
+            );
+        }
+
+        #[test]
+        fn skips_loopback_or_point_to_point() {
+            let ip = Ipv4Addr::new(127, 0, 0, 1);
+            let netmask = Ipv4Addr::new(255, 0, 0, 0);
+            assert_eq!(ipv4_broadcast(ip, netmask, true), None);
+        }
+
+        #[test]
+        fn skips_unknown_netmask() {
+            let ip = Ipv4Addr::new(10, 0, 0, 1);
+            assert_eq!(ipv4_broadcast(ip, Ipv4Addr::UNSPECIFIED, false), None);
+        }
+    }
+}
+
+#[cfg(target_os = "android")]
+pub mod android {
+    use libc::{
+        AF_INET, AF_INET6, AF_PACKET, IFF_LOOPBACK, IFF_LOWER_UP, IFF_POINTOPOINT, c_int,
+        ifaddrs, sockaddr_in, sockaddr_in6, sockaddr_ll,
+    };
+    use std::{
+        ffi::{CStr, CString},
+        fmt, io,
+        net::{Ipv4Addr, Ipv6Addr},
+        sync::OnceLock,
+    };
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct MacAddr(pub [u8; 6]);
+
+    impl fmt::Display for MacAddr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let [a, b, c, d, e, f_] = self.0;
+            write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{f_:02x}")
+        }
+    }
 
-        /// Returns `true` if the address is link-local (169.254.0.0)
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Ifv4Addr {
+        pub ip: Ipv4Addr,
+        pub netmask: Ipv4Addr,
+        pub broadcast: Option<Ipv4Addr>,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Ifv6Addr {
+        pub ip: Ipv6Addr,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum IfAddr {
+        V4(Ifv4Addr),
+        V6(Ifv6Addr),
+    }
+
+    impl IfAddr {
+        /// Returns `true` if the address is link-local (169.254.0.0 or fe80::/10)
         pub fn is_link_local(&self) -> bool {
-            self.ip.is_link_local()
+            match self {
+                IfAddr::V4(addr) => addr.ip.is_link_local(),
+                IfAddr::V6(addr) => addr.ip.is_unicast_link_local(),
+            }
         }
     }
 
-    /// Collect all IPv4 network interfaces that are considered up.
-    pub fn interfaces() -> Result<Vec<Ipv4Interface>, io::Error> {
-        let mut ifaddrs = std::ptr::null_mut();
-        let ret = unsafe { getifaddrs(&raw mut ifaddrs) };
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Interface {
+        pub name: String,
+        pub addrs: Vec<IfAddr>,
+        pub flags: u32,
+        pub mac_addr: Option<MacAddr>,
+        pub index: u32,
+    }
+
+    impl Interface {
+        /// Returns `true` if this is a loopback interface
+        pub fn is_loopback(&self) -> bool {
+            self.flags & IFF_LOOPBACK as u32 != 0
+        }
+
+        /// Returns `true` if the Interface is operational and has detected acquisition of carrier.
+        pub fn is_lower_up(&self) -> bool {
+            self.flags & IFF_LOWER_UP as u32 != 0
+        }
+    }
+
+    type GetIfAddrsFn = unsafe extern "C" fn(*mut *mut ifaddrs) -> c_int;
+    type FreeIfAddrsFn = unsafe extern "C" fn(*mut ifaddrs);
+
+    /// `getifaddrs`/`freeifaddrs` are not reliably linkable on older Android NDK
+    /// levels, so we resolve them from `libc.so` at runtime instead of linking
+    /// against them directly, and cache the result for the life of the process.
+    fn libc_getifaddrs() -> Option<(GetIfAddrsFn, FreeIfAddrsFn)> {
+        static SYMBOLS: OnceLock<Option<(usize, usize)>> = OnceLock::new();
+
+        let resolved = *SYMBOLS.get_or_init(|| unsafe {
+            let lib_name = CString::new("libc.so").ok()?;
+            let handle = libc::dlopen(lib_name.as_ptr(), libc::RTLD_LAZY | libc::RTLD_NODELETE);
+            if handle.is_null() {
+                return None;
+            }
+
+            let getifaddrs_name = CString::new("getifaddrs").ok()?;
+            let freeifaddrs_name = CString::new("freeifaddrs").ok()?;
+            let getifaddrs_sym = libc::dlsym(handle, getifaddrs_name.as_ptr());
+            let freeifaddrs_sym = libc::dlsym(handle, freeifaddrs_name.as_ptr());
+
+            if getifaddrs_sym.is_null() || freeifaddrs_sym.is_null() {
+                return None;
+            }
+
+            Some((getifaddrs_sym as usize, freeifaddrs_sym as usize))
+        });
+
+        resolved.map(|(getifaddrs_addr, freeifaddrs_addr)| unsafe {
+            (
+                std::mem::transmute::<usize, GetIfAddrsFn>(getifaddrs_addr),
+                std::mem::transmute::<usize, FreeIfAddrsFn>(freeifaddrs_addr),
+            )
+        })
+    }
+
+    /// Collect all IPv4 and IPv6 network interfaces. Interfaces are not
+    /// filtered by link state; use `is_lower_up` to check it.
+    ///
+    /// Prefers the dynamically-resolved `getifaddrs`, falling back to a raw
+    /// netlink route dump when the symbols aren't present in `libc.so`.
+    pub fn interfaces() -> Result<Vec<Interface>, io::Error> {
+        match libc_getifaddrs() {
+            Some((getifaddrs, freeifaddrs)) => via_getifaddrs(getifaddrs, freeifaddrs),
+            None => netlink::interfaces(),
+        }
+    }
+
+    fn via_getifaddrs(
+        getifaddrs: GetIfAddrsFn,
+        freeifaddrs: FreeIfAddrsFn,
+    ) -> Result<Vec<Interface>, io::Error> {
+        let mut head = std::ptr::null_mut();
+        let ret = unsafe { getifaddrs(&raw mut head) };
         if ret == -1 {
             return Err(io::Error::last_os_error());
         }
 
-        let addrs = collect(ifaddrs);
-        Ok(addrs.into_iter().filter_map(to_interface).collect())
+        let mut interfaces: Vec<Interface> = Vec::new();
+        let mut node = head;
+        while let Some(addr) = unsafe { node.as_ref() } {
+            if let Some((name, flags, if_addr)) = to_if_addr(addr) {
+                match interfaces.iter_mut().find(|iface| iface.name == name) {
+                    Some(iface) => iface.addrs.push(if_addr),
+                    None => {
+                        let index = unsafe { libc::if_nametoindex(addr.ifa_name) };
+                        interfaces.push(Interface {
+                            name,
+                            flags,
+                            addrs: vec![if_addr],
+                            mac_addr: None,
+                            index,
+                        });
+                    }
+                }
+            } else if let Some((name, mac_addr)) = to_mac_addr(addr) {
+                match interfaces.iter_mut().find(|iface| iface.name == name) {
+                    Some(iface) => iface.mac_addr = Some(mac_addr),
+                    None => {
+                        let index = unsafe { libc::if_nametoindex(addr.ifa_name) };
+                        interfaces.push(Interface {
+                            name,
+                            flags: addr.ifa_flags,
+                            addrs: Vec::new(),
+                            mac_addr: Some(mac_addr),
+                            index,
+                        });
+                    }
+                }
+            }
+            node = addr.ifa_next;
+        }
+
+        unsafe { freeifaddrs(head) };
+        Ok(interfaces)
     }
 
-    fn collect(mut ifaddrs: *mut ifaddrs) -> Vec<ifaddrs> {
-        let mut addrs = Vec::new();
+    fn to_if_addr(addr: &ifaddrs) -> Option<(String, u32, IfAddr)> {
+        let sockaddr = unsafe { addr.ifa_addr.as_ref()? };
+        let name = unsafe { CStr::from_ptr(addr.ifa_name) }
+            .to_string_lossy()
+            .to_string();
+        let flags = addr.ifa_flags;
 
-        while let Some(addr) = unsafe { ifaddrs.as_ref() } {
-            addrs.push(*addr);
-            ifaddrs = addr.ifa_next;
+        match i32::from(sockaddr.sa_family) {
+            AF_INET => {
+                let sockaddr_in = unsafe { addr.ifa_addr.cast::<sockaddr_in>().as_ref()? };
+                let ip = Ipv4Addr::from_bits(u32::from_be(sockaddr_in.sin_addr.s_addr));
+
+                let netmask_in = unsafe { addr.ifa_netmask.cast::<sockaddr_in>().as_ref() };
+                let netmask = netmask_in
+                    .map(|n| Ipv4Addr::from_bits(u32::from_be(n.sin_addr.s_addr)))
+                    .unwrap_or(Ipv4Addr::UNSPECIFIED);
+
+                let is_p2p_or_loopback = flags & (IFF_LOOPBACK | IFF_POINTOPOINT) as u32 != 0;
+                let broadcast = ipv4_broadcast(ip, netmask, is_p2p_or_loopback);
+
+                Some((
+                    name,
+                    flags,
+                    IfAddr::V4(Ifv4Addr {
+                        ip,
+                        netmask,
+                        broadcast,
+                    }),
+                ))
+            }
+            AF_INET6 => {
+                let sockaddr_in6 = unsafe { addr.ifa_addr.cast::<sockaddr_in6>().as_ref()? };
+                let ip = Ipv6Addr::from(sockaddr_in6.sin6_addr.s6_addr);
+                Some((name, flags, IfAddr::V6(Ifv6Addr { ip })))
+            }
+            _ => None,
         }
-        addrs
     }
 
-    fn to_interface(addr: ifaddrs) -> Option<Ipv4Interface> {
+    /// Derive the directed broadcast address for `ip`/`netmask`, or `None` when
+    /// the interface is loopback/point-to-point or the netmask is unknown.
+    fn ipv4_broadcast(ip: Ipv4Addr, netmask: Ipv4Addr, is_p2p_or_loopback: bool) -> Option<Ipv4Addr> {
+        (!is_p2p_or_loopback && netmask != Ipv4Addr::UNSPECIFIED)
+            .then(|| Ipv4Addr::from_bits(ip.to_bits() | !netmask.to_bits()))
+    }
+
+    fn to_mac_addr(addr: &ifaddrs) -> Option<(String, MacAddr)> {
         let sockaddr = unsafe { addr.ifa_addr.as_ref()? };
-        if i32::from(sockaddr.sa_family) != AF_INET {
+        if i32::from(sockaddr.sa_family) != AF_PACKET {
             return None;
         }
 
-        let flags = addr.ifa_flags;
-        let name = unsafe { CStr::from_ptr(addr.ifa_name) };
-        let sockaddir_in = unsafe { addr.ifa_addr.cast::<sockaddr_in>().as_ref()? };
-        let ip = Ipv4Addr::from_bits(u32::from_be(sockaddir_in.sin_addr.s_addr));
-
-        Some(Ipv4Interface {
-            name: name.to_string_lossy().to_string(),
-            ip,
-            flags,
-        })
+        let name = unsafe { CStr::from_ptr(addr.ifa_name) }
+            .to_string_lossy()
+            .to_string();
+        let sockaddr_ll = unsafe { addr.ifa_addr.cast::<sockaddr_ll>().as_ref()? };
+        let mut mac = [0u8; 6];
+        mac.copy_from_slice(&sockaddr_ll.sll_addr[..6]);
+
+        Some((name, MacAddr(mac)))
+    }
+
+    /// Raw `AF_NETLINK` route dump, used when `getifaddrs`/`freeifaddrs` can't be
+    /// resolved from `libc.so`.
+    ///
+    /// Known degradation: this only dumps `AF_INET` addresses, so a device
+    /// that falls back to this path loses `IfAddr::V6` entries entirely,
+    /// unlike the dynamically-resolved `getifaddrs` path above. It also
+    /// leaves `mac_addr` as `None` on every interface.
+    mod netlink {
+        use super::{IfAddr, Ifv4Addr, Interface, ipv4_broadcast};
+        use libc::{
+            AF_INET, AF_NETLINK, AF_UNSPEC, IFA_ADDRESS, IFA_LOCAL, IFF_LOOPBACK, IFF_POINTOPOINT,
+            IFLA_IFNAME, NETLINK_ROUTE, NLM_F_DUMP, NLM_F_REQUEST, NLMSG_DONE, NLMSG_ERROR,
+            RTM_GETADDR, RTM_GETLINK, RTM_NEWADDR, RTM_NEWLINK, c_void, ifaddrmsg, ifinfomsg,
+            nlmsghdr, rtattr, sockaddr_nl,
+        };
+        use std::{io, mem, net::Ipv4Addr};
+
+        const NLMSG_ALIGN: usize = 4;
+        const NLMSG_HDRLEN: usize = align(mem::size_of::<nlmsghdr>());
+
+        const fn align(len: usize) -> usize {
+            (len + NLMSG_ALIGN - 1) & !(NLMSG_ALIGN - 1)
+        }
+
+        /// Dump link flags via `RTM_GETLINK`, then addresses via `RTM_GETADDR`,
+        /// merging both into `Interface` entries; `mac_addr` is never set.
+        pub fn interfaces() -> Result<Vec<Interface>, io::Error> {
+            let sock = unsafe { libc::socket(AF_NETLINK, libc::SOCK_RAW, NETLINK_ROUTE) };
+            if sock < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let result = (|| {
+                let mut interfaces = dump_links(sock)?;
+                dump_addrs(sock, &mut interfaces)?;
+                Ok(interfaces)
+            })();
+
+            unsafe { libc::close(sock) };
+            result
+        }
+
+        fn dump_links(sock: i32) -> io::Result<Vec<Interface>> {
+            #[repr(C)]
+            struct Request {
+                header: nlmsghdr,
+                ifi: ifinfomsg,
+            }
+
+            let mut request: Request = unsafe { mem::zeroed() };
+            request.header.nlmsg_len = mem::size_of::<Request>() as u32;
+            request.header.nlmsg_type = RTM_GETLINK;
+            request.header.nlmsg_flags = (NLM_F_REQUEST | NLM_F_DUMP) as u16;
+            request.ifi.ifi_family = AF_UNSPEC as u8;
+
+            send_request(sock, &request)?;
+
+            let mut interfaces = Vec::new();
+            read_dump(sock, RTM_NEWLINK, |msg| {
+                if let Some(iface) = parse_new_link(msg) {
+                    interfaces.push(iface);
+                }
+            })?;
+            Ok(interfaces)
+        }
+
+        fn dump_addrs(sock: i32, interfaces: &mut Vec<Interface>) -> io::Result<()> {
+            #[repr(C)]
+            struct Request {
+                header: nlmsghdr,
+                ifa: ifaddrmsg,
+            }
+
+            let mut request: Request = unsafe { mem::zeroed() };
+            request.header.nlmsg_len = mem::size_of::<Request>() as u32;
+            request.header.nlmsg_type = RTM_GETADDR;
+            request.header.nlmsg_flags = (NLM_F_REQUEST | NLM_F_DUMP) as u16;
+            request.ifa.ifa_family = AF_INET as u8;
+
+            send_request(sock, &request)?;
+
+            read_dump(sock, RTM_NEWADDR, |msg| parse_new_addr(msg, interfaces))
+        }
+
+        fn send_request<T>(sock: i32, request: &T) -> io::Result<()> {
+            let buf = unsafe {
+                std::slice::from_raw_parts(request as *const T as *const u8, mem::size_of::<T>())
+            };
+
+            let dest: sockaddr_nl = unsafe { mem::zeroed() };
+            let ret = unsafe {
+                libc::sendto(
+                    sock,
+                    buf.as_ptr() as *const c_void,
+                    buf.len(),
+                    0,
+                    &dest as *const sockaddr_nl as *const libc::sockaddr,
+                    mem::size_of::<sockaddr_nl>() as u32,
+                )
+            };
+
+            if ret < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+
+        /// Read dump replies until `NLMSG_DONE`, invoking `on_message` with the
+        /// raw bytes of each message whose type matches `msg_type`.
+        fn read_dump(
+            sock: i32,
+            msg_type: u16,
+            mut on_message: impl FnMut(&[u8]),
+        ) -> io::Result<()> {
+            let mut buf = vec![0u8; 16 * 1024];
+
+            'recv: loop {
+                let len =
+                    unsafe { libc::recv(sock, buf.as_mut_ptr() as *mut c_void, buf.len(), 0) };
+                if len < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                let mut offset = 0usize;
+                while offset + NLMSG_HDRLEN <= len as usize {
+                    let header = unsafe { &*(buf[offset..].as_ptr() as *const nlmsghdr) };
+
+                    // A message shorter than its own header is malformed; bail
+                    // out instead of looping forever on `align(0) == 0`.
+                    if (header.nlmsg_len as usize) < NLMSG_HDRLEN {
+                        break 'recv;
+                    }
+
+                    if header.nlmsg_type as i32 == NLMSG_DONE {
+                        break 'recv;
+                    } else if header.nlmsg_type as i32 == NLMSG_ERROR {
+                        return Err(io::Error::other(
+                            "netlink error response while dumping",
+                        ));
+                    } else if header.nlmsg_type == msg_type {
+                        let end = (offset + header.nlmsg_len as usize).min(len as usize);
+                        on_message(&buf[offset..end]);
+                    }
+
+                    offset += align(header.nlmsg_len as usize);
+                }
+            }
+
+            Ok(())
+        }
+
+        fn parse_new_link(msg: &[u8]) -> Option<Interface> {
+            let ifi_len = mem::size_of::<ifinfomsg>();
+            if msg.len() < NLMSG_HDRLEN + ifi_len {
+                return None;
+            }
+
+            let ifi = unsafe { &*(msg[NLMSG_HDRLEN..].as_ptr() as *const ifinfomsg) };
+            let index = ifi.ifi_index as u32;
+            let flags = ifi.ifi_flags as u32;
+
+            let mut offset = NLMSG_HDRLEN + align(ifi_len);
+            let mut name = None;
+
+            while offset + mem::size_of::<rtattr>() <= msg.len() {
+                let attr = unsafe { &*(msg[offset..].as_ptr() as *const rtattr) };
+                let attr_len = attr.rta_len as usize;
+                if attr_len < mem::size_of::<rtattr>() || offset + attr_len > msg.len() {
+                    break;
+                }
+
+                let payload = &msg[offset + mem::size_of::<rtattr>()..offset + attr_len];
+                if attr.rta_type == IFLA_IFNAME {
+                    name = std::ffi::CStr::from_bytes_until_nul(payload)
+                        .ok()
+                        .map(|s| s.to_string_lossy().to_string());
+                }
+
+                offset += align(attr_len);
+            }
+
+            Some(Interface {
+                name: name.unwrap_or_else(|| format!("if{index}")),
+                flags,
+                addrs: Vec::new(),
+                mac_addr: None,
+                index,
+            })
+        }
+
+        fn parse_new_addr(msg: &[u8], interfaces: &mut Vec<Interface>) {
+            let ifa_len = mem::size_of::<ifaddrmsg>();
+            if msg.len() < NLMSG_HDRLEN + ifa_len {
+                return;
+            }
+
+            let ifa = unsafe { &*(msg[NLMSG_HDRLEN..].as_ptr() as *const ifaddrmsg) };
+            let prefix_len = ifa.ifa_prefixlen;
+            let index = ifa.ifa_index as u32;
+
+            let mut offset = NLMSG_HDRLEN + align(ifa_len);
+            let mut ip = None;
+
+            while offset + mem::size_of::<rtattr>() <= msg.len() {
+                let attr = unsafe { &*(msg[offset..].as_ptr() as *const rtattr) };
+                let attr_len = attr.rta_len as usize;
+                if attr_len < mem::size_of::<rtattr>() || offset + attr_len > msg.len() {
+                    break;
+                }
+
+                let payload = &msg[offset + mem::size_of::<rtattr>()..offset + attr_len];
+                if (attr.rta_type == IFA_LOCAL || attr.rta_type == IFA_ADDRESS) && payload.len() >= 4
+                {
+                    ip = Some(Ipv4Addr::new(payload[0], payload[1], payload[2], payload[3]));
+                }
+
+                offset += align(attr_len);
+            }
+
+            let Some(ip) = ip else { return };
+            let netmask = if prefix_len == 0 {
+                Ipv4Addr::UNSPECIFIED
+            } else {
+                Ipv4Addr::from_bits(!0u32 << (32 - prefix_len as u32))
+            };
+
+            match interfaces.iter_mut().find(|iface| iface.index == index) {
+                Some(iface) => {
+                    let is_p2p_or_loopback =
+                        iface.flags & (IFF_LOOPBACK | IFF_POINTOPOINT) as u32 != 0;
+                    let broadcast = ipv4_broadcast(ip, netmask, is_p2p_or_loopback);
+                    iface
+                        .addrs
+                        .push(IfAddr::V4(Ifv4Addr { ip, netmask, broadcast }));
+                }
+                None => {
+                    // The address dump named an interface the link dump didn't
+                    // report (e.g. a rename race); fall back to a flagless entry.
+                    let broadcast = ipv4_broadcast(ip, netmask, false);
+                    interfaces.push(Interface {
+                        name: format!("if{index}"),
+                        flags: 0,
+                        addrs: vec![IfAddr::V4(Ifv4Addr { ip, netmask, broadcast })],
+                        mac_addr: None,
+                        index,
+                    });
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn align_rounds_up_to_four_bytes() {
+                assert_eq!(align(0), 0);
+                assert_eq!(align(1), 4);
+                assert_eq!(align(4), 4);
+                assert_eq!(align(5), 8);
+                assert_eq!(align(17), 20);
+            }
+
+            #[test]
+            fn parse_new_link_reads_ifname_attribute() {
+                #[repr(C)]
+                struct Msg {
+                    header: nlmsghdr,
+                    ifi: ifinfomsg,
+                    attr_header: rtattr,
+                    attr_value: [u8; 5], // "eth0\0"
+                }
+
+                let mut msg: Msg = unsafe { mem::zeroed() };
+                msg.ifi.ifi_index = 3;
+                msg.ifi.ifi_flags = IFF_LOOPBACK as u32;
+                msg.attr_header.rta_len = (mem::size_of::<rtattr>() + 5) as u16;
+                msg.attr_header.rta_type = IFLA_IFNAME;
+                msg.attr_value = *b"eth0\0";
+
+                let bytes = unsafe {
+                    std::slice::from_raw_parts(
+                        &msg as *const Msg as *const u8,
+                        mem::size_of::<Msg>(),
+                    )
+                };
+
+                let iface = parse_new_link(bytes).expect("parses a link message");
+                assert_eq!(iface.index, 3);
+                assert_eq!(iface.name, "eth0");
+                assert_eq!(iface.flags & IFF_LOOPBACK as u32, IFF_LOOPBACK as u32);
+            }
+        }
     }
 }
 
 #[cfg(target_family = "windows")]
 pub mod winders {
+    use std::fmt;
     use std::io;
-    use std::net::Ipv4Addr;
+    use std::net::{Ipv4Addr, Ipv6Addr};
 
     use windows::Win32::Foundation::ERROR_BUFFER_OVERFLOW;
     use windows::Win32::Foundation::ERROR_SUCCESS;
@@ -87,20 +779,94 @@ pub mod winders {
     use windows::Win32::NetworkManagement::IpHelper::GetAdaptersAddresses;
     use windows::Win32::NetworkManagement::IpHelper::IF_TYPE_SOFTWARE_LOOPBACK;
     use windows::Win32::NetworkManagement::IpHelper::IP_ADAPTER_ADDRESSES_LH;
+    use windows::Win32::NetworkManagement::IpHelper::IP_ADAPTER_UNICAST_ADDRESS_LH;
     use windows::Win32::NetworkManagement::Ndis::IfOperStatusUp;
     use windows::Win32::Networking::WinSock::AF_INET;
+    use windows::Win32::Networking::WinSock::AF_INET6;
+    use windows::Win32::Networking::WinSock::AF_UNSPEC;
     use windows::Win32::Networking::WinSock::SOCKADDR_IN;
+    use windows::Win32::Networking::WinSock::SOCKADDR_IN6;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct MacAddr(pub [u8; 6]);
+
+    impl fmt::Display for MacAddr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let [a, b, c, d, e, f_] = self.0;
+            write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{f_:02x}")
+        }
+    }
 
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Ipv4Interface {
-        pub name: String,
+    pub struct Ifv4Addr {
         pub ip: Ipv4Addr,
+        pub netmask: Ipv4Addr,
+        pub broadcast: Option<Ipv4Addr>,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Ifv6Addr {
+        pub ip: Ipv6Addr,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum IfAddr {
+        V4(Ifv4Addr),
+        V6(Ifv6Addr),
+    }
+
+    impl IfAddr {
+        /// Returns `true` if the address is link-local (169.254.0.0 or fe80::/10)
+        pub fn is_link_local(&self) -> bool {
+            match self {
+                IfAddr::V4(addr) => addr.ip.is_link_local(),
+                IfAddr::V6(addr) => addr.ip.is_unicast_link_local(),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Interface {
+        pub name: String,
+        pub addrs: Vec<IfAddr>,
         pub if_type: u32,
         pub oper_status: i32,
+        pub mac_addr: Option<MacAddr>,
+        pub index: u32,
     }
 
-    impl Ipv4Interface {
-        /// Returns `true` if this is a loopback address (127.0.0.0)
+    fn prefix_to_netmask(prefix_length: u8) -> Ipv4Addr {
+        match prefix_length {
+            0 => Ipv4Addr::UNSPECIFIED,
+            // Some adapters report a sentinel like 255 when the prefix length
+            // wasn't set; treat anything outside a valid IPv4 prefix as unknown
+            // rather than shifting by a negative amount.
+            1..=32 => Ipv4Addr::from_bits(!0u32 << (32 - prefix_length as u32)),
+            _ => Ipv4Addr::UNSPECIFIED,
+        }
+    }
+
+    #[cfg(test)]
+    mod prefix_to_netmask_tests {
+        use super::*;
+
+        #[test]
+        fn known_prefixes() {
+            assert_eq!(prefix_to_netmask(0), Ipv4Addr::UNSPECIFIED);
+            assert_eq!(prefix_to_netmask(8), Ipv4Addr::new(255, 0, 0, 0));
+            assert_eq!(prefix_to_netmask(24), Ipv4Addr::new(255, 255, 255, 0));
+            assert_eq!(prefix_to_netmask(32), Ipv4Addr::new(255, 255, 255, 255));
+        }
+
+        #[test]
+        fn out_of_range_prefix_is_treated_as_unknown() {
+            assert_eq!(prefix_to_netmask(33), Ipv4Addr::UNSPECIFIED);
+            assert_eq!(prefix_to_netmask(255), Ipv4Addr::UNSPECIFIED);
+        }
+    }
+
+    impl Interface {
+        /// Returns `true` if this is a loopback interface
         pub fn is_loopback(&self) -> bool {
             self.if_type == IF_TYPE_SOFTWARE_LOOPBACK
         }
@@ -109,21 +875,17 @@ pub mod winders {
         pub fn is_up(&self) -> bool {
             self.oper_status == IfOperStatusUp.0
         }
-
-        /// Returns `true` if the address is link-local (169.254.0.0)
-        pub fn is_link_local(&self) -> bool {
-            self.ip.is_link_local()
-        }
     }
 
-    /// Collect all IPv4 network interfaces that are considered up.
-    pub fn interfaces() -> Result<Vec<Ipv4Interface>, io::Error> {
+    /// Collect all IPv4 and IPv6 network interfaces. Interfaces are not
+    /// filtered by operational status; use `is_up` to check it.
+    pub fn interfaces() -> Result<Vec<Interface>, io::Error> {
         unsafe {
             // We don't know what the actual size requirement is, so we start with the recommended 15kb
             // buffer and if we overflow on the first attempt `buffer_size` will be populated
             // with the correct size and we can call `GetAdaptersAddress` again.
             let mut buffer_size = 15000u32;
-            let family = AF_INET.0 as u32;
+            let family = AF_UNSPEC.0 as u32;
             let mut buffer;
             let mut ifaddrs;
 
@@ -138,7 +900,6 @@ pub mod winders {
                     Some(ifaddrs),
                     &mut buffer_size,
                 );
-                dbg!(family, ifaddrs, buffer_size, res);
 
                 match WIN32_ERROR(res) {
                     ERROR_SUCCESS => break,
@@ -164,25 +925,95 @@ pub mod winders {
         addrs
     }
 
-    fn to_interface(addr: IP_ADAPTER_ADDRESSES_LH) -> Option<Ipv4Interface> {
+    fn to_interface(addr: IP_ADAPTER_ADDRESSES_LH) -> Option<Interface> {
         unsafe {
             let name = addr.FriendlyName.to_string().ok()?;
             let if_type = addr.IfType;
             let oper_status = addr.OperStatus.0;
-            let unicast_addr = addr.FirstUnicastAddress.as_ref()?;
-            let sockaddr_in = unicast_addr
-                .Address
-                .lpSockaddr
-                .cast::<SOCKADDR_IN>()
-                .as_ref()?;
-            let ip = Ipv4Addr::from_bits(u32::from_be(sockaddr_in.sin_addr.S_un.S_addr));
-
-            Some(Ipv4Interface {
+            let addrs = collect_unicast_addrs(addr.FirstUnicastAddress, if_type);
+            let mac_addr = to_mac_addr(&addr);
+            let index = if addr.IfIndex != 0 {
+                addr.IfIndex
+            } else {
+                addr.Ipv6IfIndex
+            };
+
+            Some(Interface {
                 name,
-                ip,
+                addrs,
                 if_type,
                 oper_status,
+                mac_addr,
+                index,
             })
         }
     }
+
+    fn to_mac_addr(addr: &IP_ADAPTER_ADDRESSES_LH) -> Option<MacAddr> {
+        let len = addr.PhysicalAddressLength as usize;
+        if len != 6 {
+            return None;
+        }
+        let mut mac = [0u8; 6];
+        mac.copy_from_slice(&addr.PhysicalAddress[..6]);
+        Some(MacAddr(mac))
+    }
+
+    fn collect_unicast_addrs(
+        mut node: *mut IP_ADAPTER_UNICAST_ADDRESS_LH,
+        if_type: u32,
+    ) -> Vec<IfAddr> {
+        let mut addrs = Vec::new();
+
+        while let Some(unicast_addr) = unsafe { node.as_ref() } {
+            if let Some(if_addr) = to_if_addr(unicast_addr, if_type) {
+                addrs.push(if_addr);
+            }
+            node = unicast_addr.Next;
+        }
+
+        addrs
+    }
+
+    fn to_if_addr(unicast_addr: &IP_ADAPTER_UNICAST_ADDRESS_LH, if_type: u32) -> Option<IfAddr> {
+        let sa_family = unsafe { (*unicast_addr.Address.lpSockaddr).sa_family };
+
+        match sa_family {
+            x if x == AF_INET => {
+                let sockaddr_in = unsafe {
+                    unicast_addr
+                        .Address
+                        .lpSockaddr
+                        .cast::<SOCKADDR_IN>()
+                        .as_ref()?
+                };
+                let ip = Ipv4Addr::from_bits(u32::from_be(unsafe {
+                    sockaddr_in.sin_addr.S_un.S_addr
+                }));
+
+                let netmask = prefix_to_netmask(unicast_addr.OnLinkPrefixLength);
+                let broadcast = (if_type != IF_TYPE_SOFTWARE_LOOPBACK
+                    && netmask != Ipv4Addr::UNSPECIFIED)
+                    .then(|| Ipv4Addr::from_bits(ip.to_bits() | !netmask.to_bits()));
+
+                Some(IfAddr::V4(Ifv4Addr {
+                    ip,
+                    netmask,
+                    broadcast,
+                }))
+            }
+            x if x == AF_INET6 => {
+                let sockaddr_in6 = unsafe {
+                    unicast_addr
+                        .Address
+                        .lpSockaddr
+                        .cast::<SOCKADDR_IN6>()
+                        .as_ref()?
+                };
+                let ip = Ipv6Addr::from(unsafe { sockaddr_in6.sin6_addr.u.Byte });
+                Some(IfAddr::V6(Ifv6Addr { ip }))
+            }
+            _ => None,
+        }
+    }
 }